@@ -1,16 +1,29 @@
+use std::marker::PhantomData;
 use std::ops::Deref;
+use std::ops::DerefMut;
+use std::ptr::null_mut;
 use std::ptr::NonNull;
 use std::sync::atomic::fence;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicPtr;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Mutex;
 
+// repr(C) pins the field order so the byte offset from `&value` back to the
+// vault base is well-defined, which `Ark::from_raw` relies on.
+#[repr(C)]
 struct ArkVault<T>
 where
     T: Send + Sync,
 {
     rc: AtomicUsize,
     value: T,
+    // The pool this vault's allocation should be returned to when its refcount
+    // hits zero, or null for vaults owned by the global allocator. While a vault
+    // sits on a pool's free-list its `value` is dropped; the allocation is held
+    // by the shard's stack and `rc` is left stale until the vault is reused.
+    pool: *const ArkPool<T>,
 }
 
 impl<T> ArkVault<T>
@@ -21,6 +34,7 @@ where
         ArkVault {
             rc: AtomicUsize::new(1),
             value,
+            pool: std::ptr::null(),
         }
     }
 
@@ -29,6 +43,35 @@ where
     }
 }
 
+/// Releases a vault whose refcount has reached zero, honouring its `pool` field.
+///
+/// Global-allocator vaults are handed straight back to `Box`; pooled vaults have
+/// their `value` dropped and the emptied allocation returned to the pool's
+/// free-list, falling back to the allocator only when the current thread's shard
+/// of the pool is at capacity.
+/// This is the single free path shared by [`Ark`]'s drop and the `make_mut`
+/// unshare.
+///
+/// # Safety
+///
+/// The refcount must have just dropped to zero, so nothing else refers to the
+/// vault, and `pool` (if non-null) must still be live.
+unsafe fn free_vault<T>(vault: *mut ArkVault<T>)
+where
+    T: Send + Sync,
+{
+    let pool = (*vault).pool;
+
+    if pool.is_null() {
+        drop(Box::from_raw(vault));
+    } else {
+        std::ptr::drop_in_place(&mut (*vault).value);
+        if !(*pool).recycle(vault) {
+            std::alloc::dealloc(vault as *mut u8, std::alloc::Layout::new::<ArkVault<T>>());
+        }
+    }
+}
+
 pub struct Ark<T>
 where
     T: Send + Sync,
@@ -71,6 +114,80 @@ where
             None
         }
     }
+
+    pub fn borrow(&self) -> ArkBorrow<'_, T> {
+        ArkBorrow {
+            vault: self.vault,
+            _vault: PhantomData,
+        }
+    }
+
+    pub fn into_raw(self) -> *const T {
+        // Safety: the vault is live while `self` exists.
+        let value = unsafe { std::ptr::addr_of!((*self.vault.as_ptr()).value) };
+
+        // Hand the strong reference to the caller: forget `self` so neither the
+        // refcount nor the drop path runs. The count is reclaimed by `from_raw`.
+        std::mem::forget(self);
+
+        value
+    }
+
+    /// Reconstructs an owning `Ark` from a pointer returned by [`into_raw`].
+    ///
+    /// [`into_raw`]: Ark::into_raw
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been produced by `Ark::into_raw` from this crate, and
+    /// `from_raw` must be called exactly once per `into_raw` — it takes back the
+    /// strong reference that `into_raw` handed out.
+    pub unsafe fn from_raw(ptr: *const T) -> Ark<T> {
+        let offset = std::mem::offset_of!(ArkVault<T>, value);
+        let vault = (ptr as *const u8).sub(offset) as *mut ArkVault<T>;
+
+        Ark {
+            vault: NonNull::new_unchecked(vault),
+        }
+    }
+
+    pub fn as_raw(&self) -> *const T {
+        // Safety: the vault is live for as long as this Ark is borrowed.
+        unsafe { std::ptr::addr_of!((*self.vault.as_ptr()).value) }
+    }
+
+    pub fn make_mut(ark: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        if ark.vault().rc.load(Ordering::Acquire) == 1 {
+            // Safety: The refcount is 1, so this Ark holds the only reference to
+            // the vault and its value, and the mutable borrow of `ark` keeps it
+            // that way for the returned reference's lifetime.
+            unsafe { &mut ark.vault.as_mut().value }
+        } else {
+            // The vault is shared, so unshare it: clone the value into a fresh
+            // vault that only we own, then drop our reference to the old one.
+            let fresh = Box::into_raw(Box::new(ArkVault::new(ark.vault().value.clone())));
+            let fresh = NonNull::new(fresh).unwrap();
+
+            let old_rc = ark.vault().rc.fetch_sub(1, Ordering::Release);
+            if old_rc == 1 {
+                fence(Ordering::Acquire);
+                // Safety: The old refcount just dropped to zero (a concurrent
+                // handle was dropped between the load above and here), so the
+                // old vault can be freed through its owning pool, if any.
+                unsafe {
+                    free_vault(ark.vault.as_ptr());
+                }
+            }
+
+            ark.vault = fresh;
+
+            // Safety: The fresh vault has rc == 1 and is owned solely by `ark`.
+            unsafe { &mut ark.vault.as_mut().value }
+        }
+    }
 }
 
 impl<T> Clone for Ark<T>
@@ -94,10 +211,12 @@ where
         let old_rc = self.vault().rc.fetch_sub(1, Ordering::Release);
         if old_rc == 1 {
             fence(Ordering::Acquire);
+
             // Safety: Refcount just dropped to zero, so nothing refers to the
-            // vault and its value, so it can be accessed and dropped.
+            // vault and its value, so it can be freed; `pool` (if any) outlives
+            // the vaults it created.
             unsafe {
-                drop(Box::from_raw(self.vault.as_ptr()));
+                free_vault(self.vault.as_ptr());
             }
         }
     }
@@ -113,6 +232,479 @@ where
     }
 }
 
+/// A non-owning borrow of an [`Ark`]'s vault.
+///
+/// Obtained from [`Ark::borrow`], it references the vault with a lifetime but
+/// performs no `fetch_add`/`fetch_sub`, so passing it down through many frames
+/// that only read the value costs no atomic traffic. When a frame genuinely
+/// needs to extend the value's lifetime beyond the borrow, it pays for a single
+/// increment with [`to_ark`](ArkBorrow::to_ark).
+pub struct ArkBorrow<'a, T>
+where
+    T: Send + Sync,
+{
+    vault: NonNull<ArkVault<T>>,
+    _vault: PhantomData<&'a ArkVault<T>>,
+}
+
+unsafe impl<T> Send for ArkBorrow<'_, T> where T: Send + Sync {}
+unsafe impl<T> Sync for ArkBorrow<'_, T> where T: Send + Sync {}
+
+impl<T> ArkBorrow<'_, T>
+where
+    T: Send + Sync,
+{
+    pub fn to_ark(self) -> Ark<T> {
+        // Safety: the borrow keeps the originating Ark alive, so the vault is
+        // live; claim a fresh strong reference for the owned handle.
+        unsafe {
+            self.vault.as_ref().rc.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ark { vault: self.vault }
+    }
+}
+
+impl<T> Deref for ArkBorrow<'_, T>
+where
+    T: Send + Sync,
+{
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        // Safety: the borrow's lifetime keeps the originating Ark, and thus the
+        // vault, alive.
+        unsafe { self.vault.as_ref().value_ref() }
+    }
+}
+
+/// A uniquely-owned vault whose refcount is known to be 1 at the type level.
+///
+/// Because no other handle can exist, `UniqueArk` hands out `&mut T` through
+/// [`DerefMut`] with no atomic load or runtime check. This is the tool for
+/// building a value up in place — pushing into a `Vec`, filling a buffer —
+/// before freezing it with [`share`](UniqueArk::share) into a cheaply clonable
+/// [`Ark`]. The type is intentionally not `Clone`, which is what keeps the
+/// uniqueness invariant sound.
+pub struct UniqueArk<T>
+where
+    T: Send + Sync,
+{
+    vault: NonNull<ArkVault<T>>,
+}
+
+unsafe impl<T> Send for UniqueArk<T> where T: Send + Sync {}
+unsafe impl<T> Sync for UniqueArk<T> where T: Send + Sync {}
+
+impl<T> UniqueArk<T>
+where
+    T: Send + Sync,
+{
+    pub fn new(value: T) -> UniqueArk<T> {
+        let vault = Box::into_raw(Box::new(ArkVault::new(value)));
+
+        UniqueArk {
+            vault: NonNull::new(vault).unwrap(),
+        }
+    }
+
+    pub fn share(self) -> Ark<T> {
+        let vault = self.vault;
+
+        // The vault already has rc == 1, so the shared Ark reinterprets the same
+        // allocation without copying; skip our own drop so the vault lives on.
+        std::mem::forget(self);
+
+        Ark { vault }
+    }
+}
+
+impl<T> Deref for UniqueArk<T>
+where
+    T: Send + Sync,
+{
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        // Safety: we are the sole owner of the vault.
+        unsafe { self.vault.as_ref().value_ref() }
+    }
+}
+
+impl<T> DerefMut for UniqueArk<T>
+where
+    T: Send + Sync,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: rc is 1 by construction and this type is not Clone, so this is
+        // the only reference to the vault and its value.
+        unsafe { &mut self.vault.as_mut().value }
+    }
+}
+
+impl<T> Drop for UniqueArk<T>
+where
+    T: Send + Sync,
+{
+    fn drop(&mut self) {
+        // Safety: rc is 1 and we are the sole owner, so the vault can be freed.
+        unsafe {
+            drop(Box::from_raw(self.vault.as_ptr()));
+        }
+    }
+}
+
+/// The number of reader slots in the global debt registry, i.e. the maximum
+/// number of threads that may concurrently hold a debt slot. Each such thread
+/// owns a slot exclusively for its lifetime, so reads never clobber one another;
+/// a thread that cannot claim a free slot panics rather than silently sharing.
+const DEBT_SLOTS: usize = 256;
+
+/// Global registry of pointers that readers are in the middle of cloning. A
+/// reader publishes the vault pointer it is about to increment into its own slot
+/// before touching the refcount, so a concurrent `AtomicArk::swap` can see the
+/// in-flight read and transfer a strong count instead of freeing the vault.
+///
+/// The pointers are type-erased to `*mut ()` so a single registry serves every
+/// `AtomicArk<T>`; comparisons are by address only.
+static DEBT: [AtomicPtr<()>; DEBT_SLOTS] =
+    [const { AtomicPtr::new(null_mut()) }; DEBT_SLOTS];
+
+/// Occupancy flags guarding the debt slots. A thread flips its slot's flag to
+/// `true` on first [`AtomicArk::load`] and owns that slot exclusively until it
+/// exits, when [`DebtSlot`]'s drop flips it back. Exclusive ownership is what
+/// stops one reader's published pointer from overwriting another's.
+static DEBT_OWNED: [AtomicBool; DEBT_SLOTS] = [const { AtomicBool::new(false) }; DEBT_SLOTS];
+
+/// A thread's exclusive claim on one debt slot, released when the thread exits.
+struct DebtSlot {
+    index: usize,
+}
+
+impl DebtSlot {
+    fn claim() -> DebtSlot {
+        for (index, owned) in DEBT_OWNED.iter().enumerate() {
+            if owned
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                return DebtSlot { index };
+            }
+        }
+
+        panic!("AtomicArk debt registry exhausted: more than {DEBT_SLOTS} threads reading concurrently");
+    }
+}
+
+impl Drop for DebtSlot {
+    fn drop(&mut self) {
+        // Clear any leftover pointer before releasing the slot for reuse.
+        DEBT[self.index].store(null_mut(), Ordering::SeqCst);
+        DEBT_OWNED[self.index].store(false, Ordering::Release);
+    }
+}
+
+thread_local! {
+    static DEBT_INDEX: DebtSlot = DebtSlot::claim();
+}
+
+/// A lock-free cell holding an [`Ark`] that can be swapped atomically.
+///
+/// Readers call [`load`](AtomicArk::load) to take a consistent snapshot while a
+/// writer publishes a replacement with [`store`](AtomicArk::store) or
+/// [`swap`](AtomicArk::swap), with no mutex in the picture. `load` is guaranteed
+/// never to observe a torn or freed vault: it publishes the pointer it is about
+/// to clone into the global debt registry, and a racing writer transfers it a
+/// strong count rather than dropping the vault out from under the pending
+/// increment.
+pub struct AtomicArk<T>
+where
+    T: Send + Sync,
+{
+    ptr: AtomicPtr<ArkVault<T>>,
+}
+
+unsafe impl<T> Send for AtomicArk<T> where T: Send + Sync {}
+unsafe impl<T> Sync for AtomicArk<T> where T: Send + Sync {}
+
+impl<T> AtomicArk<T>
+where
+    T: Send + Sync,
+{
+    pub fn new(ark: Ark<T>) -> AtomicArk<T> {
+        let ptr = ark.vault.as_ptr();
+        std::mem::forget(ark);
+        AtomicArk {
+            ptr: AtomicPtr::new(ptr),
+        }
+    }
+
+    pub fn load(&self) -> Ark<T> {
+        let slot = &DEBT[DEBT_INDEX.with(|slot| slot.index)];
+
+        let vault = loop {
+            let vault = self.ptr.load(Ordering::Acquire);
+            slot.store(vault as *mut (), Ordering::SeqCst);
+
+            // Re-read the pointer now that our debt is visible. If it still
+            // matches, any future swap of this pointer is guaranteed to observe
+            // the slot and keep the vault alive for our increment below. If it
+            // changed, a writer raced ahead of us; clear the debt and retry.
+            if self.ptr.load(Ordering::SeqCst) == vault {
+                break vault;
+            }
+
+            // A writer raced ahead of us; clear the debt and retry. Use `swap`,
+            // not a bare store: a writer that saw our slot may already have
+            // transferred us a strong count on `vault` and cleared the slot
+            // itself. If so, release that count here, or it leaks for good.
+            if slot.swap(null_mut(), Ordering::AcqRel) != vault as *mut () {
+                // Dropping the handed-over count as an `Ark` runs the free path
+                // if the writer has since released its own reference.
+                drop(Ark {
+                    // Safety: the writer's handover left a strong count on `vault`.
+                    vault: unsafe { NonNull::new_unchecked(vault) },
+                });
+            }
+        };
+
+        // Safety: `vault` is pinned alive by our published debt slot until we
+        // settle it below, so the increment cannot touch freed memory.
+        unsafe {
+            (*vault).rc.fetch_add(1, Ordering::Relaxed);
+        }
+
+        if slot.swap(null_mut(), Ordering::AcqRel) != vault as *mut () {
+            // A writer already cleared our slot, meaning it transferred a strong
+            // count to us; release the extra so the refcount stays balanced.
+            //
+            // Safety: we hold a strong count on `vault` from the increment above.
+            unsafe {
+                (*vault).rc.fetch_sub(1, Ordering::Release);
+            }
+        }
+
+        Ark {
+            // Safety: `vault` came from a live `Ark`, so it is non-null.
+            vault: unsafe { NonNull::new_unchecked(vault) },
+        }
+    }
+
+    pub fn swap(&self, ark: Ark<T>) -> Ark<T> {
+        let new = ark.vault.as_ptr();
+        std::mem::forget(ark);
+
+        // SeqCst (not merely AcqRel) so this store joins the single total order
+        // of the reader's slot-publish and pointer-reread. Without it, StoreLoad
+        // reordering would let this scan read a reader's slot as null while that
+        // reader's re-read still sees `old` — neither side observes the other,
+        // and we would free `old` out from under the reader's pending increment.
+        let old = self.ptr.swap(new, Ordering::SeqCst);
+        let old_erased = old as *mut ();
+
+        // We still own `old`'s strong count, so it cannot be freed while we scan.
+        // Transfer a count to every reader caught mid-`load` on `old` so their
+        // pending increment balances out against our handover instead of racing
+        // a free.
+        for slot in &DEBT {
+            if slot.load(Ordering::SeqCst) == old_erased {
+                // Safety: we hold a strong count on `old`.
+                unsafe {
+                    (*old).rc.fetch_add(1, Ordering::Relaxed);
+                }
+
+                if slot
+                    .compare_exchange(
+                        old_erased,
+                        null_mut(),
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    )
+                    .is_err()
+                {
+                    // The reader settled its own debt first; take the count back.
+                    //
+                    // Safety: we still hold a strong count on `old`.
+                    unsafe {
+                        (*old).rc.fetch_sub(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        }
+
+        Ark {
+            // Safety: `old` was owned by this cell and is non-null.
+            vault: unsafe { NonNull::new_unchecked(old) },
+        }
+    }
+
+    pub fn store(&self, ark: Ark<T>) {
+        drop(self.swap(ark));
+    }
+}
+
+impl<T> Drop for AtomicArk<T>
+where
+    T: Send + Sync,
+{
+    fn drop(&mut self) {
+        let vault = self.ptr.load(Ordering::Relaxed);
+        drop(Ark {
+            // Safety: the stored pointer is a live, owned `Ark` vault.
+            vault: unsafe { NonNull::new_unchecked(vault) },
+        });
+    }
+}
+
+/// Hands each thread a stable index used only to pick an [`ArkPool`] shard. It
+/// is independent of the `AtomicArk` debt registry, so pool users never consume
+/// a debt slot.
+static SHARD_NEXT: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SHARD_INDEX: usize = SHARD_NEXT.fetch_add(1, Ordering::Relaxed);
+}
+
+/// A per-thread (by hash) shard of an [`ArkPool`]'s free-list.
+///
+/// Threads are hashed onto shards to spread contention, so a shard can still be
+/// touched by more than one thread; the stack is therefore guarded by a short
+/// `Mutex` rather than left as a lock-free Treiber stack, which would be exposed
+/// to ABA on its pop path. The `Vec` retains at most the pool's high-water mark
+/// of reclaimed vaults and reserves that capacity up front, so steady-state
+/// recycling performs no allocation of its own.
+struct PoolShard<T>
+where
+    T: Send + Sync,
+{
+    stack: Mutex<Vec<*mut ArkVault<T>>>,
+}
+
+/// An opt-in recycler for [`ArkVault`] allocations.
+///
+/// For workloads that churn through many short-lived `Ark`s of the same `T`,
+/// [`create`](ArkPool::create) pulls a freed vault off a recycled free-list
+/// (allocating only when the list is empty) and an `Ark` created this way
+/// returns its emptied allocation to the list on final drop instead of calling
+/// the global allocator. The free-list is sharded per thread to keep contention
+/// low — each shard a short-held `Mutex` over a capacity-bounded stack — and
+/// each shard retains at most `capacity` vaults so memory is eventually released
+/// back to the allocator.
+///
+/// An `ArkPool` must outlive every `Ark` it creates; because violating that is a
+/// use-after-free, [`create`](ArkPool::create) is `unsafe`.
+pub struct ArkPool<T>
+where
+    T: Send + Sync,
+{
+    shards: Box<[PoolShard<T>]>,
+    capacity: usize,
+}
+
+unsafe impl<T> Send for ArkPool<T> where T: Send + Sync {}
+unsafe impl<T> Sync for ArkPool<T> where T: Send + Sync {}
+
+impl<T> ArkPool<T>
+where
+    T: Send + Sync,
+{
+    pub fn new(capacity: usize) -> ArkPool<T> {
+        let shards = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+
+        let mut slots = Vec::with_capacity(shards);
+        for _ in 0..shards {
+            slots.push(PoolShard {
+                stack: Mutex::new(Vec::with_capacity(capacity)),
+            });
+        }
+
+        ArkPool {
+            shards: slots.into_boxed_slice(),
+            capacity,
+        }
+    }
+
+    /// Creates an `Ark` whose vault is recycled through this pool.
+    ///
+    /// # Safety
+    ///
+    /// The pool must outlive every `Ark` it creates. Such an `Ark` dereferences
+    /// the pool on its final drop to return its vault to the free-list, so
+    /// dropping the pool first is a use-after-free — the caller is responsible
+    /// for ordering the pool's lifetime after all of its outstanding handles.
+    pub unsafe fn create(&self, value: T) -> Ark<T> {
+        let vault = match self.pop() {
+            Some(vault) => {
+                // A recycled allocation: its `value` slot is uninitialized and
+                // its `rc` is stale from its previous life, so write both afresh.
+                //
+                // Safety: `pop` handed us exclusive ownership of this vault.
+                unsafe {
+                    // The recycled `value` slot is uninitialized (its old value
+                    // was dropped in `free_vault`), so write through a raw
+                    // pointer rather than forming a `&mut` to uninit memory.
+                    std::ptr::write(std::ptr::addr_of_mut!((*vault).value), value);
+                    (*vault).rc.store(1, Ordering::Relaxed);
+                    (*vault).pool = self as *const ArkPool<T>;
+                }
+                vault
+            }
+            None => Box::into_raw(Box::new(ArkVault {
+                rc: AtomicUsize::new(1),
+                value,
+                pool: self as *const ArkPool<T>,
+            })),
+        };
+
+        Ark {
+            vault: NonNull::new(vault).unwrap(),
+        }
+    }
+
+    fn shard(&self) -> &PoolShard<T> {
+        &self.shards[SHARD_INDEX.with(|index| *index) % self.shards.len()]
+    }
+
+    /// Pushes an emptied vault onto this thread's shard. Returns `false` when
+    /// the shard is already at the high-water mark, leaving the caller to free
+    /// the allocation.
+    fn recycle(&self, vault: *mut ArkVault<T>) -> bool {
+        let mut stack = self.shard().stack.lock().unwrap();
+        if stack.len() >= self.capacity {
+            return false;
+        }
+        stack.push(vault);
+        true
+    }
+
+    fn pop(&self) -> Option<*mut ArkVault<T>> {
+        self.shard().stack.lock().unwrap().pop()
+    }
+}
+
+impl<T> Drop for ArkPool<T>
+where
+    T: Send + Sync,
+{
+    fn drop(&mut self) {
+        for shard in self.shards.iter() {
+            let stack = shard.stack.lock().unwrap();
+            for &vault in stack.iter() {
+                // Pooled vaults have already had their `value` dropped, so just
+                // release the backing allocation.
+                //
+                // Safety: the free-list exclusively owns these allocations.
+                unsafe {
+                    std::alloc::dealloc(
+                        vault as *mut u8,
+                        std::alloc::Layout::new::<ArkVault<T>>(),
+                    );
+                }
+            }
+        }
+    }
+}
+
 fn main() {
     let v = vec![];
     let v = Ark::new(Mutex::new(v));
@@ -133,3 +725,182 @@ fn main() {
     assert!(v.lock().unwrap().contains(&17));
     assert!(v.lock().unwrap().contains(&42));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rc<T>(ark: &Ark<T>) -> usize
+    where
+        T: Send + Sync,
+    {
+        ark.vault().rc.load(Ordering::Relaxed)
+    }
+
+    #[test]
+    fn make_mut_mutates_unique_in_place() {
+        let mut a = Ark::new(vec![1, 2, 3]);
+        let before = a.as_raw();
+        Ark::make_mut(&mut a).push(4);
+        assert_eq!(&*a, &[1, 2, 3, 4]);
+        // Uniquely owned, so no unsharing copy happened.
+        assert_eq!(a.as_raw(), before);
+    }
+
+    #[test]
+    fn make_mut_unshares_when_shared() {
+        let mut a = Ark::new(vec![1, 2, 3]);
+        let b = a.clone();
+        assert_eq!(rc(&a), 2);
+
+        Ark::make_mut(&mut a).push(4);
+
+        assert_eq!(&*a, &[1, 2, 3, 4]);
+        assert_eq!(&*b, &[1, 2, 3]);
+        // `a` moved to a fresh, unique vault; `b` kept the old one.
+        assert_eq!(rc(&a), 1);
+        assert_eq!(rc(&b), 1);
+    }
+
+    #[test]
+    fn into_raw_from_raw_round_trip() {
+        let a = Ark::new(String::from("hello"));
+        assert_eq!(rc(&a), 1);
+
+        let raw = a.into_raw();
+        // Safety: `raw` came from `into_raw` and is reclaimed exactly once here.
+        let b = unsafe { Ark::from_raw(raw) };
+
+        assert_eq!(&*b, "hello");
+        // The strong count was handed across untouched.
+        assert_eq!(rc(&b), 1);
+    }
+
+    #[test]
+    fn as_raw_borrows_without_consuming() {
+        let a = Ark::new(42u32);
+        // Safety: `a` keeps the vault live across the read.
+        assert_eq!(unsafe { *a.as_raw() }, 42);
+        assert_eq!(*a, 42);
+    }
+
+    #[test]
+    fn unique_ark_build_then_share() {
+        let mut u = UniqueArk::new(Vec::new());
+        u.push(1);
+        u.push(2);
+        u.push(3);
+
+        let a = u.share();
+        assert_eq!(&*a, &[1, 2, 3]);
+        assert_eq!(rc(&a), 1);
+
+        // Sharing reuses the same allocation, so cloning is cheap afterwards.
+        let b = a.clone();
+        assert_eq!(rc(&a), 2);
+        drop(b);
+        assert_eq!(rc(&a), 1);
+    }
+
+    #[test]
+    fn ark_borrow_skips_refcount_until_promoted() {
+        let a = Ark::new(99u64);
+        let borrowed = a.borrow();
+        assert_eq!(*borrowed, 99);
+        // Borrowing does not touch the refcount.
+        assert_eq!(rc(&a), 1);
+
+        let owned = borrowed.to_ark();
+        assert_eq!(*owned, 99);
+        assert_eq!(rc(&a), 2);
+    }
+
+    #[test]
+    fn pool_recycles_freed_vault() {
+        let pool = ArkPool::<u64>::new(8);
+
+        // Safety: every `Ark` is dropped before the pool below.
+        let a = unsafe { pool.create(1) };
+        let first = a.as_raw();
+        drop(a);
+
+        let b = unsafe { pool.create(2) };
+        // The freed allocation was pulled back off the free-list and reused.
+        assert_eq!(b.as_raw(), first);
+        assert_eq!(*b, 2);
+        drop(b);
+    }
+
+    #[test]
+    fn atomic_ark_load_store_swap() {
+        let cell = AtomicArk::new(Ark::new(1u64));
+        assert_eq!(*cell.load(), 1);
+
+        let old = cell.swap(Ark::new(2));
+        assert_eq!(*old, 1);
+        assert_eq!(*cell.load(), 2);
+
+        cell.store(Ark::new(3));
+        assert_eq!(*cell.load(), 3);
+    }
+
+    /// A payload that tracks how many instances are currently alive, so a test
+    /// can assert the refcounting eventually drops every value it created — a
+    /// stray transferred strong count would keep one alive and trip the check.
+    struct Live {
+        alive: std::sync::Arc<AtomicUsize>,
+    }
+
+    impl Live {
+        fn new(alive: &std::sync::Arc<AtomicUsize>) -> Live {
+            alive.fetch_add(1, Ordering::Relaxed);
+            Live {
+                alive: alive.clone(),
+            }
+        }
+    }
+
+    impl Drop for Live {
+        fn drop(&mut self) {
+            self.alive.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    // Run under Miri to exercise the reader/writer debt handshake:
+    // `cargo +nightly miri test atomic_ark_concurrent_load_swap`.
+    #[test]
+    fn atomic_ark_concurrent_load_swap() {
+        let alive = std::sync::Arc::new(AtomicUsize::new(0));
+        let cell = AtomicArk::new(Ark::new(Live::new(&alive)));
+
+        std::thread::scope(|scope| {
+            let cell = &cell;
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..500 {
+                        // Each load must observe a live, non-torn vault.
+                        let snapshot = cell.load();
+                        let _ = &*snapshot;
+                    }
+                });
+            }
+            for _ in 0..2 {
+                let alive = &alive;
+                scope.spawn(move || {
+                    for _ in 0..500 {
+                        cell.store(Ark::new(Live::new(alive)));
+                    }
+                });
+            }
+        });
+
+        // The final load is valid; then dropping the cell releases its last
+        // handle and every payload created under contention must be gone — a
+        // strong count leaked by the load/swap handshake would strand one here.
+        let snapshot = cell.load();
+        let _ = &*snapshot;
+        drop(snapshot);
+        drop(cell);
+        assert_eq!(alive.load(Ordering::Relaxed), 0);
+    }
+}